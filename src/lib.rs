@@ -1,5 +1,11 @@
 use std::ops::{Deref, DerefMut};
 use std::future::Future;
+use std::pin::Pin;
+use std::fmt;
+use std::time::Duration;
+use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Waker};
 
 /// Trait for types that require async cleanup.
 ///
@@ -13,26 +19,440 @@ pub trait AsyncDrop {
     fn async_drop(self) -> Self::Dropper;
 }
 
+/// Error returned when an [`Executor`] cannot spawn a cleanup future.
+///
+/// This usually means there is no async runtime available at drop time (for
+/// example, the `tokio` feature is enabled but we are not inside a Tokio
+/// context, or no runtime feature is enabled at all).
+#[derive(Debug)]
+pub struct SpawnError {
+    message: String,
+}
+
+impl SpawnError {
+    /// Build a `SpawnError` with a human-readable explanation.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to spawn async cleanup task: {}", self.message)
+    }
+}
+
+impl std::error::Error for SpawnError {}
+
+/// Something that can run a detached cleanup future to completion.
+///
+/// In [`DropMode::Spawn`] the wrapper does not drive the future itself; it
+/// hands it to an `Executor` so the spawning strategy stays independent from
+/// the `Drop` glue. [`DropMode::Blocking`] does not use the executor. Ship-in
+/// implementations exist for Tokio and async-std behind their feature flags,
+/// and [`AsyncDropx::with_executor`] lets you plug in your own.
+pub trait Executor {
+    /// Spawn `fut` so it runs to completion in the background.
+    ///
+    /// Returns [`SpawnError`] if no runtime is available to accept the task.
+    fn spawn(&self, fut: Pin<Box<dyn Future<Output = ()> + Send>>) -> Result<(), SpawnError>;
+}
+
+/// The executor used by [`AsyncDropx::new`].
+///
+/// It auto-detects the active runtime at drop time: if a Tokio runtime handle
+/// is current the future goes there, otherwise it falls back to async-std's
+/// global executor. This is what lets a binary link both runtimes at once —
+/// the decision is made per-drop instead of at compile time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultExecutor;
+
+impl Executor for DefaultExecutor {
+    fn spawn(&self, _fut: Pin<Box<dyn Future<Output = ()> + Send>>) -> Result<(), SpawnError> {
+        #[cfg(feature = "tokio")]
+        {
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                handle.spawn(_fut);
+                return Ok(());
+            }
+        }
+
+        #[cfg(feature = "async-std")]
+        {
+            // async-std spawns onto a global executor, so no handle check is needed.
+            async_std::task::spawn(_fut);
+            return Ok(());
+        }
+
+        #[allow(unreachable_code)]
+        Err(SpawnError::new(
+            "no async runtime available (enable the `tokio` or `async-std` feature, \
+             or run inside a runtime context)",
+        ))
+    }
+}
+
+/// [`Executor`] backed by the current Tokio runtime handle.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioExecutor;
+
+#[cfg(feature = "tokio")]
+impl Executor for TokioExecutor {
+    fn spawn(&self, fut: Pin<Box<dyn Future<Output = ()> + Send>>) -> Result<(), SpawnError> {
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(fut);
+                Ok(())
+            }
+            Err(_) => Err(SpawnError::new("not inside a Tokio runtime context")),
+        }
+    }
+}
+
+/// [`Executor`] backed by async-std's global task spawner.
+#[cfg(feature = "async-std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncStdExecutor;
+
+#[cfg(feature = "async-std")]
+impl Executor for AsyncStdExecutor {
+    fn spawn(&self, fut: Pin<Box<dyn Future<Output = ()> + Send>>) -> Result<(), SpawnError> {
+        async_std::task::spawn(fut);
+        Ok(())
+    }
+}
+
+/// Process-global bookkeeping for in-flight detached cleanups.
+///
+/// Every spawned dropper is wrapped so the count goes up when it is spawned and
+/// back down when it finishes — even if it panics — and waiters parked in
+/// [`join_all_pending`] are woken once the count reaches zero.
+struct Registry {
+    pending: AtomicUsize,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl Registry {
+    fn global() -> &'static Registry {
+        static REGISTRY: OnceLock<Registry> = OnceLock::new();
+        REGISTRY.get_or_init(|| Registry {
+            pending: AtomicUsize::new(0),
+            wakers: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn enter(&self) {
+        self.pending.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn leave(&self) {
+        // `fetch_sub` returns the previous value, so `1` means we just hit zero.
+        if self.pending.fetch_sub(1, Ordering::SeqCst) == 1 {
+            let parked = std::mem::take(&mut *self.wakers.lock().unwrap());
+            for waker in parked {
+                waker.wake();
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    fn register_waker(&self, waker: &Waker) {
+        let mut parked = self.wakers.lock().unwrap();
+        if !parked.iter().any(|w| w.will_wake(waker)) {
+            parked.push(waker.clone());
+        }
+    }
+}
+
+/// RAII guard that records one in-flight cleanup for its whole lifetime.
+///
+/// Wrapping the spawned future in this guard means the count is decremented on
+/// the normal path and on unwind alike.
+struct PendingGuard;
+
+impl PendingGuard {
+    fn new() -> Self {
+        Registry::global().enter();
+        PendingGuard
+    }
+}
+
+impl Drop for PendingGuard {
+    fn drop(&mut self) {
+        Registry::global().leave();
+    }
+}
+
+/// Wrap a cleanup future so its execution is tracked by the global registry.
+fn track(
+    future: Pin<Box<dyn Future<Output = ()> + Send>>,
+) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+    // Construct the guard synchronously so the count reflects spawn time, not
+    // first poll. Otherwise `join_all_pending` called right after a drop would
+    // observe zero and return before the cleanup has even started.
+    let guard = PendingGuard::new();
+    Box::pin(async move {
+        let _guard = guard;
+        future.await;
+    })
+}
+
+/// Future that resolves once no detached cleanups are outstanding.
+struct JoinPending;
+
+impl Future for JoinPending {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let registry = Registry::global();
+        if registry.count() == 0 {
+            return Poll::Ready(());
+        }
+        registry.register_waker(cx.waker());
+        // Re-check after parking: a cleanup may have finished between the first
+        // read and registering the waker.
+        if registry.count() == 0 {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Wait until every detached async drop spawned so far has finished.
+///
+/// Detached cleanups (the default [`DropMode::Spawn`]) run in the background,
+/// so a process that exits immediately may cut them off. Call this at the end
+/// of `main` or during graceful shutdown to flush in-flight cleanups
+/// deterministically instead of guessing a `sleep` duration.
+///
+/// Cleanups scheduled by [`LocalAsyncDropx`] are not tracked and are not
+/// waited for.
+pub async fn join_all_pending() {
+    JoinPending.await
+}
+
+/// Like [`join_all_pending`] but gives up after `timeout`.
+///
+/// Returns `true` if all cleanups drained in time, `false` if the deadline hit
+/// first.
+#[cfg(feature = "tokio")]
+pub async fn join_all_pending_timeout(timeout: Duration) -> bool {
+    tokio::time::timeout(timeout, join_all_pending()).await.is_ok()
+}
+
+/// async-std variant of [`join_all_pending_timeout`].
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub async fn join_all_pending_timeout(timeout: Duration) -> bool {
+    async_std::future::timeout(timeout, join_all_pending()).await.is_ok()
+}
+
+/// Fallback when no runtime feature is enabled: there is no timer, so
+/// `_timeout` is ignored and this waits like [`join_all_pending`] (cleanups
+/// spawned through a custom [`Executor`] are still tracked).
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+pub async fn join_all_pending_timeout(_timeout: Duration) -> bool {
+    join_all_pending().await;
+    true
+}
+
+/// How the wrapper disposes of the cleanup future when it is dropped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DropMode {
+    /// Hand the future to the [`Executor`] and return immediately (fire-and-forget).
+    #[default]
+    Spawn,
+    /// Drive the future to completion before `Drop` returns, blocking the
+    /// dropping thread (the future may be polled on a helper thread).
+    ///
+    /// See [`AsyncDropx::blocking`]. Pair with [`AsyncDropx::set_timeout`] so a
+    /// hung cleanup future can't deadlock the thread.
+    Blocking,
+}
+
 /// Wrapper that ensures `async_drop` is called when the object goes out of scope.
 ///
 /// This wrapper implements `Deref` and `DerefMut`, so you can use it just like the inner type.
-/// When it goes out of scope, `Drop` is called, which takes the inner value and spawns
-/// the future returned by `async_drop` on the active async runtime.
+/// When it goes out of scope, `Drop` is called, which takes the inner value and hands
+/// the future returned by `async_drop` to an [`Executor`].
+///
+/// [`new`](Self::new) auto-detects the current runtime via [`DefaultExecutor`];
+/// use [`with_executor`](Self::with_executor) to inject a specific one.
+///
+/// By default the cleanup future is spawned detached ([`DropMode::Spawn`]). Use
+/// [`blocking`](Self::blocking) to instead drive it to completion inside `Drop`,
+/// optionally bounded by [`set_timeout`](Self::set_timeout).
 ///
 /// Supported runtimes:
 /// - `tokio` (requires `tokio` feature)
 /// - `async-std` (requires `async-std` feature)
 pub struct AsyncDropx<T: AsyncDrop + Send + 'static> {
     inner: Option<T>,
+    executor: Box<dyn Executor + Send>,
+    mode: DropMode,
+    timeout: Option<Duration>,
+    on_timeout: Option<Box<dyn FnMut() + Send>>,
 }
 
 impl<T: AsyncDrop + Send + 'static> AsyncDropx<T> {
     /// Create a new `AsyncDropx` wrapping the given value.
+    ///
+    /// Cleanup is routed through [`DefaultExecutor`], which picks the active
+    /// runtime at drop time.
     pub fn new(inner: T) -> Self {
-        Self { inner: Some(inner) }
+        Self::with_executor(inner, DefaultExecutor)
+    }
+
+    /// Create a new `AsyncDropx` that spawns its cleanup future on `executor`.
+    ///
+    /// Use this to target a specific runtime or a custom executor instead of
+    /// relying on auto-detection.
+    pub fn with_executor<E: Executor + Send + 'static>(inner: T, executor: E) -> Self {
+        Self {
+            inner: Some(inner),
+            executor: Box::new(executor),
+            mode: DropMode::Spawn,
+            timeout: None,
+            on_timeout: None,
+        }
+    }
+
+    /// Create a new `AsyncDropx` that drives its cleanup future to completion
+    /// inside `Drop` instead of spawning it detached.
+    ///
+    /// This avoids the classic footgun where the scope exits and `main` has to
+    /// `sleep` to let a fire-and-forget cleanup finish. Combine with
+    /// [`set_timeout`](Self::set_timeout) to bound how long the dropping thread
+    /// will wait.
+    pub fn blocking(inner: T) -> Self {
+        let mut this = Self::new(inner);
+        this.mode = DropMode::Blocking;
+        this
+    }
+
+    /// Set how the cleanup future is disposed of when this wrapper is dropped.
+    pub fn set_mode(mut self, mode: DropMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Bound how long a [`DropMode::Blocking`] drop will drive the cleanup
+    /// future before giving up.
+    ///
+    /// When the deadline elapses the future is abandoned and the timeout
+    /// handler (see [`on_timeout`](Self::on_timeout)) is invoked, so a hung
+    /// cleanup can't deadlock the dropping thread.
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Install a callback invoked when a [`DropMode::Blocking`] cleanup exceeds
+    /// its [`set_timeout`](Self::set_timeout) deadline.
+    ///
+    /// Without a handler the timeout is reported on stderr. When no runtime
+    /// feature is enabled the cleanup cannot run at all and is reported the
+    /// same way, whether or not a timeout was set.
+    pub fn on_timeout<F: FnMut() + Send + 'static>(mut self, handler: F) -> Self {
+        self.on_timeout = Some(Box::new(handler));
+        self
+    }
+
+    /// Drive the cleanup future to completion, respecting `self.timeout`.
+    fn run_blocking(&mut self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+        let timeout = self.timeout;
+        if block_on_dropper(future, timeout) {
+            match self.on_timeout.as_mut() {
+                Some(handler) => handler(),
+                None => eprintln!(
+                    "AsyncDropx: cleanup future exceeded timeout of {timeout:?}; abandoning it"
+                ),
+            }
+        }
+    }
+}
+
+/// Drive a cleanup future to completion on the current thread, optionally
+/// bounded by `timeout`. Returns `true` if the deadline elapsed first.
+#[cfg(feature = "tokio")]
+fn block_on_dropper(
+    future: Pin<Box<dyn Future<Output = ()> + Send>>,
+    timeout: Option<Duration>,
+) -> bool {
+    let driver = async move {
+        match timeout {
+            Some(deadline) => tokio::time::timeout(deadline, future).await.is_err(),
+            None => {
+                future.await;
+                false
+            }
+        }
+    };
+
+    match tokio::runtime::Handle::try_current() {
+        // On a multi-threaded runtime we can hand the current worker back to the
+        // scheduler while we block, so we don't starve other tasks.
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(driver))
+        }
+        // Otherwise we may be inside a current-thread runtime, where both
+        // `block_in_place` and starting a nested runtime on this thread panic.
+        // Drive a dedicated current-thread runtime on a separate thread and
+        // join it — the cleanup future is `Send`, so it can move across.
+        _ => {
+            let joined = std::thread::spawn(move || {
+                match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                    Ok(rt) => rt.block_on(driver),
+                    Err(err) => {
+                        eprintln!("AsyncDropx: could not build a fallback runtime for blocking drop: {err}");
+                        false
+                    }
+                }
+            })
+            .join();
+            match joined {
+                Ok(timed_out) => timed_out,
+                Err(_) => {
+                    eprintln!("AsyncDropx: blocking-drop worker thread panicked");
+                    false
+                }
+            }
+        }
     }
 }
 
+/// async-std variant of [`block_on_dropper`], used when Tokio is not enabled.
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+fn block_on_dropper(
+    future: Pin<Box<dyn Future<Output = ()> + Send>>,
+    timeout: Option<Duration>,
+) -> bool {
+    async_std::task::block_on(async move {
+        match timeout {
+            Some(deadline) => async_std::future::timeout(deadline, future).await.is_err(),
+            None => {
+                future.await;
+                false
+            }
+        }
+    })
+}
+
+/// Fallback used when no runtime feature is enabled: nothing can drive the
+/// future, so it is dropped and reported as abandoned (`true`).
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+fn block_on_dropper(
+    _future: Pin<Box<dyn Future<Output = ()> + Send>>,
+    _timeout: Option<Duration>,
+) -> bool {
+    eprintln!("AsyncDropx: no async runtime feature enabled; cannot run blocking drop");
+    true
+}
+
 impl<T: AsyncDrop + Send + 'static> Deref for AsyncDropx<T> {
     type Target = T;
 
@@ -50,40 +470,289 @@ impl<T: AsyncDrop + Send + 'static> DerefMut for AsyncDropx<T> {
 impl<T: AsyncDrop + Send + 'static> Drop for AsyncDropx<T> {
     fn drop(&mut self) {
         if let Some(inner) = self.inner.take() {
-            let _future = inner.async_drop();
-            
+            let future = Box::pin(inner.async_drop());
+            match self.mode {
+                DropMode::Spawn => {
+                    if let Err(err) = self.executor.spawn(track(future)) {
+                        eprintln!("AsyncDropx: {err}. Cleanup leaked.");
+                    }
+                }
+                DropMode::Blocking => self.run_blocking(future),
+            }
+        }
+    }
+}
+
+/// Trait for types whose async cleanup touches `!Send` state.
+///
+/// This mirrors [`AsyncDrop`] but drops the `Send` bound on the dropper, so the
+/// cleanup future may capture `!Send` values (an `Rc`, a non-`Send` client
+/// handle, a `Box<dyn Error>` held across an await point). Wrap such a type in
+/// [`LocalAsyncDropx`], which schedules the cleanup on the current thread.
+pub trait AsyncDropLocal {
+    /// The future returned by `async_drop`. Not required to be `Send`.
+    type Dropper: Future<Output = ()> + 'static;
+
+    /// Perform the async cleanup.
+    /// This method consumes the object.
+    fn async_drop(self) -> Self::Dropper;
+}
+
+/// `!Send` counterpart to [`AsyncDropx`] for single-threaded resources.
+///
+/// On drop the cleanup future is spawned on the current thread via
+/// `tokio::task::spawn_local`, which requires an enclosing
+/// [`LocalSet`](tokio::task::LocalSet). If no local-spawning context is
+/// available a diagnostic is emitted instead of silently leaking the cleanup.
+///
+/// Only the `tokio` feature supports local drop; async-std has no local spawner,
+/// so under async-std the cleanup is reported and leaked.
+pub struct LocalAsyncDropx<T: AsyncDropLocal + 'static> {
+    inner: Option<T>,
+}
+
+impl<T: AsyncDropLocal + 'static> LocalAsyncDropx<T> {
+    /// Create a new `LocalAsyncDropx` wrapping the given value.
+    pub fn new(inner: T) -> Self {
+        Self { inner: Some(inner) }
+    }
+}
+
+impl<T: AsyncDropLocal + 'static> Deref for LocalAsyncDropx<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().expect("Inner value is missing - this should never happen unless already dropped")
+    }
+}
+
+impl<T: AsyncDropLocal + 'static> DerefMut for LocalAsyncDropx<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().expect("Inner value is missing - this should never happen unless already dropped")
+    }
+}
+
+impl<T: AsyncDropLocal + 'static> Drop for LocalAsyncDropx<T> {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            let _future: Pin<Box<dyn Future<Output = ()>>> = Box::pin(inner.async_drop());
+
             #[cfg(feature = "tokio")]
             {
-                if let Ok(handle) = tokio::runtime::Handle::try_current() {
-                    handle.spawn(_future);
-                    return;
+                // `spawn_local` panics when there is no `LocalSet` in scope.
+                // Catch it so current-thread users get a clear diagnostic
+                // rather than an abort, while a properly-scoped LocalSet keeps
+                // working transparently.
+                let spawned = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    tokio::task::spawn_local(_future);
+                }));
+                if spawned.is_err() {
+                    eprintln!(
+                        "LocalAsyncDropx: no `LocalSet` in scope. Run your code inside a \
+                         `tokio::task::LocalSet` so `!Send` cleanup can be scheduled. Cleanup leaked."
+                    );
                 }
             }
-            
-            #[cfg(feature = "async-std")]
+
+            // async-std exposes no local spawner, so `!Send` cleanup can't be
+            // scheduled onto its global executor. `LocalAsyncDropx` therefore
+            // only supports the `tokio` feature; under async-std we emit a
+            // diagnostic rather than silently leak.
+            #[cfg(all(feature = "async-std", not(feature = "tokio")))]
             {
-                // async-std doesn't strictly require a handle check, but we can just spawn it.
-                // However, if we are not in a runtime, this might panic or fail?
-                // async_std::task::spawn usually works if the runtime is initialized.
-                async_std::task::spawn(_future);
-                return;
+                eprintln!(
+                    "LocalAsyncDropx: `!Send` cleanup is unsupported under async-std (no local \
+                     spawner). Enable the `tokio` feature and use a `LocalSet`. Cleanup leaked."
+                );
             }
 
-            // If we reach here, we couldn't spawn the task.
-            // This might happen if:
-            // 1. No feature flags are enabled.
-            // 2. Tokio feature is enabled but we are not in a Tokio context.
-            // 3. Async-std feature is enabled but something went wrong (though async-std is global).
-            
             #[cfg(not(any(feature = "tokio", feature = "async-std")))]
             {
-                eprintln!("AsyncDropx: No async runtime feature enabled (tokio/async-std). Cleanup leaked.");
+                eprintln!("LocalAsyncDropx: no async runtime feature enabled (tokio/async-std). Cleanup leaked.");
             }
-            
-            #[cfg(any(feature = "tokio", feature = "async-std"))]
-            {
-                 eprintln!("AsyncDropx: Failed to spawn async cleanup task. Runtime might be missing or shut down.");
+        }
+    }
+}
+
+/// What to do after a fallible cleanup attempt returns `Err`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Re-run the dropper (subject to the [`RetryPolicy`] attempt budget).
+    Retry,
+    /// Give up; the error is handed to the terminal fallback, which logs it.
+    Stop,
+}
+
+/// Bounds how many times a failing dropper is retried and how long to wait
+/// between attempts.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first. `1` disables retrying.
+    pub max_attempts: usize,
+    /// Optional delay between attempts, using the runtime's sleep primitive.
+    pub backoff: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 1, backoff: None }
+    }
+}
+
+/// Trait for types whose async cleanup can fail.
+///
+/// Unlike [`AsyncDrop`], the dropper resolves to a `Result`, and it borrows
+/// `self` so it can be re-run on failure. Wrap the type in
+/// [`FallibleAsyncDropx`] to surface and react to cleanup errors instead of
+/// swallowing them inside a detached task.
+pub trait TryAsyncDrop {
+    /// The error produced by a failed cleanup.
+    type Error;
+    /// The future returned by `async_drop`.
+    type Dropper: Future<Output = Result<(), Self::Error>> + Send + 'static;
+
+    /// Perform one async cleanup attempt.
+    fn async_drop(&self) -> Self::Dropper;
+}
+
+/// Wrapper for [`TryAsyncDrop`] resources that runs cleanup with an error
+/// handler and a bounded retry policy.
+///
+/// On drop the cleanup future is spawned (tracked by the global registry, like
+/// [`AsyncDropx`]). If it resolves to `Err`, the user handler decides whether to
+/// [`Retry`](RetryDecision::Retry) or [`Stop`](RetryDecision::Stop); retries are
+/// capped by the [`RetryPolicy`] and may back off between attempts. Once the
+/// budget is exhausted or the handler stops, the final error is logged.
+pub struct FallibleAsyncDropx<T, F>
+where
+    T: TryAsyncDrop + Send + 'static,
+    T::Error: fmt::Display + Send,
+    F: FnMut(T::Error) -> RetryDecision + Send + 'static,
+{
+    inner: Option<T>,
+    handler: Option<F>,
+    policy: RetryPolicy,
+}
+
+impl<T, F> FallibleAsyncDropx<T, F>
+where
+    T: TryAsyncDrop + Send + 'static,
+    T::Error: fmt::Display + Send,
+    F: FnMut(T::Error) -> RetryDecision + Send + 'static,
+{
+    /// Wrap `inner`, invoking `handler` whenever a cleanup attempt fails.
+    pub fn new(inner: T, handler: F) -> Self {
+        Self { inner: Some(inner), handler: Some(handler), policy: RetryPolicy::default() }
+    }
+
+    /// Replace the retry policy wholesale.
+    pub fn set_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Set the maximum number of attempts (including the first).
+    pub fn set_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.policy.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sleep for `backoff` between retry attempts.
+    pub fn set_backoff(mut self, backoff: Duration) -> Self {
+        self.policy.backoff = Some(backoff);
+        self
+    }
+}
+
+impl<T, F> Deref for FallibleAsyncDropx<T, F>
+where
+    T: TryAsyncDrop + Send + 'static,
+    T::Error: fmt::Display + Send,
+    F: FnMut(T::Error) -> RetryDecision + Send + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().expect("Inner value is missing - this should never happen unless already dropped")
+    }
+}
+
+impl<T, F> DerefMut for FallibleAsyncDropx<T, F>
+where
+    T: TryAsyncDrop + Send + 'static,
+    T::Error: fmt::Display + Send,
+    F: FnMut(T::Error) -> RetryDecision + Send + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().expect("Inner value is missing - this should never happen unless already dropped")
+    }
+}
+
+impl<T, F> Drop for FallibleAsyncDropx<T, F>
+where
+    T: TryAsyncDrop + Send + 'static,
+    T::Error: fmt::Display + Send,
+    F: FnMut(T::Error) -> RetryDecision + Send + 'static,
+{
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            let handler = self.handler.take();
+            let policy = self.policy;
+            let future = Box::pin(run_with_retry(inner, handler, policy));
+            if let Err(err) = DefaultExecutor.spawn(track(future)) {
+                eprintln!("FallibleAsyncDropx: {err}. Cleanup leaked.");
             }
         }
     }
 }
+
+/// Drive a fallible dropper, applying the handler and retry policy.
+async fn run_with_retry<T, F>(inner: T, mut handler: Option<F>, policy: RetryPolicy)
+where
+    T: TryAsyncDrop + Send + 'static,
+    T::Error: fmt::Display + Send,
+    F: FnMut(T::Error) -> RetryDecision + Send + 'static,
+{
+    let mut attempt = 0usize;
+    loop {
+        attempt += 1;
+        let err = match inner.async_drop().await {
+            Ok(()) => return,
+            Err(err) => err,
+        };
+
+        // Render before the handler consumes the error, so we can still log a
+        // terminal failure.
+        let rendered = format!("{err}");
+        let decision = match handler.as_mut() {
+            Some(handler) => handler(err),
+            None => RetryDecision::Stop,
+        };
+
+        if decision == RetryDecision::Stop || attempt >= policy.max_attempts {
+            eprintln!("FallibleAsyncDropx: cleanup failed after {attempt} attempt(s): {rendered}");
+            return;
+        }
+
+        if let Some(backoff) = policy.backoff {
+            runtime_sleep(backoff).await;
+        }
+    }
+}
+
+/// Sleep using the active runtime's timer.
+#[cfg(feature = "tokio")]
+async fn runtime_sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// async-std variant of [`runtime_sleep`].
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+async fn runtime_sleep(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+/// Fallback when no runtime is enabled: nothing can drive the timer, so the
+/// backoff is skipped.
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+async fn runtime_sleep(_duration: Duration) {}
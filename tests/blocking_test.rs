@@ -0,0 +1,55 @@
+use async_dropx::AsyncDrop;
+use async_dropx::AsyncDropx;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use std::pin::Pin;
+use std::future::Future;
+
+struct SlowResource {
+    done: Arc<AtomicBool>,
+    delay: Duration,
+}
+
+impl AsyncDrop for SlowResource {
+    type Dropper = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn async_drop(self) -> Self::Dropper {
+        let done = self.done.clone();
+        let delay = self.delay;
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            done.store(true, Ordering::SeqCst);
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_blocking_drop_finishes_before_returning() {
+    let done = Arc::new(AtomicBool::new(false));
+
+    {
+        let resource = SlowResource { done: done.clone(), delay: Duration::from_millis(20) };
+        let _wrapper = AsyncDropx::blocking(resource);
+        // wrapper is dropped here; the cleanup must complete synchronously.
+    }
+
+    assert!(done.load(Ordering::SeqCst), "Blocking drop returned before cleanup finished");
+}
+
+#[tokio::test]
+async fn test_blocking_drop_honours_timeout() {
+    let done = Arc::new(AtomicBool::new(false));
+    let timed_out = Arc::new(AtomicBool::new(false));
+
+    {
+        let resource = SlowResource { done: done.clone(), delay: Duration::from_secs(10) };
+        let timed_out = timed_out.clone();
+        let _wrapper = AsyncDropx::blocking(resource)
+            .set_timeout(Duration::from_millis(20))
+            .on_timeout(move || timed_out.store(true, Ordering::SeqCst));
+    }
+
+    assert!(!done.load(Ordering::SeqCst), "Cleanup should have been abandoned at the deadline");
+    assert!(timed_out.load(Ordering::SeqCst), "Timeout handler was not invoked");
+}
@@ -0,0 +1,55 @@
+use async_dropx::{AsyncDrop, AsyncDropx, Executor, SpawnError};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+use std::pin::Pin;
+use std::future::Future;
+
+struct TestResource {
+    dropped_flag: Arc<Notify>,
+}
+
+impl AsyncDrop for TestResource {
+    type Dropper = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn async_drop(self) -> Self::Dropper {
+        let flag = self.dropped_flag.clone();
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            flag.notify_one();
+        })
+    }
+}
+
+// A custom executor that records how many futures it was asked to spawn and
+// then forwards them to the current Tokio runtime.
+struct CountingExecutor {
+    spawned: Arc<AtomicUsize>,
+}
+
+impl Executor for CountingExecutor {
+    fn spawn(&self, fut: Pin<Box<dyn Future<Output = ()> + Send>>) -> Result<(), SpawnError> {
+        self.spawned.fetch_add(1, Ordering::SeqCst);
+        tokio::runtime::Handle::current().spawn(fut);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_custom_executor_is_used() {
+    let flag = Arc::new(Notify::new());
+    let spawned = Arc::new(AtomicUsize::new(0));
+
+    {
+        let resource = TestResource { dropped_flag: flag.clone() };
+        let _wrapper = AsyncDropx::with_executor(
+            resource,
+            CountingExecutor { spawned: spawned.clone() },
+        );
+    }
+
+    let result = tokio::time::timeout(Duration::from_secs(1), flag.notified()).await;
+    assert!(result.is_ok(), "Async drop did not complete in time");
+    assert_eq!(spawned.load(Ordering::SeqCst), 1, "Custom executor was not used");
+}
@@ -0,0 +1,67 @@
+use async_dropx::{TryAsyncDrop, FallibleAsyncDropx, RetryDecision, join_all_pending};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::pin::Pin;
+use std::future::Future;
+
+// A resource whose cleanup fails a fixed number of times before succeeding.
+struct FlakyResource {
+    attempts: Arc<AtomicUsize>,
+    fail_until: usize,
+}
+
+impl TryAsyncDrop for FlakyResource {
+    type Error = String;
+    type Dropper = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+    fn async_drop(&self) -> Self::Dropper {
+        let attempts = self.attempts.clone();
+        let fail_until = self.fail_until;
+        Box::pin(async move {
+            let n = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if n <= fail_until {
+                Err(format!("flush failed on attempt {n}"))
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_retry_eventually_succeeds() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let handler_calls = Arc::new(AtomicUsize::new(0));
+
+    {
+        let calls = handler_calls.clone();
+        let _wrapper = FallibleAsyncDropx::new(
+            FlakyResource { attempts: attempts.clone(), fail_until: 2 },
+            move |_err| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                RetryDecision::Retry
+            },
+        )
+        .set_max_attempts(5);
+    }
+
+    join_all_pending().await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 3, "Expected two failures then a success");
+    assert_eq!(handler_calls.load(Ordering::SeqCst), 2, "Handler should fire once per failure");
+}
+
+#[tokio::test]
+async fn test_stop_decision_halts_retrying() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+
+    {
+        let _wrapper = FallibleAsyncDropx::new(
+            FlakyResource { attempts: attempts.clone(), fail_until: 10 },
+            |_err| RetryDecision::Stop,
+        )
+        .set_max_attempts(5);
+    }
+
+    join_all_pending().await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 1, "Stop should prevent any retry");
+}
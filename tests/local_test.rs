@@ -0,0 +1,42 @@
+use async_dropx::{AsyncDropLocal, LocalAsyncDropx};
+use std::rc::Rc;
+use std::cell::Cell;
+use std::time::Duration;
+use std::pin::Pin;
+use std::future::Future;
+use tokio::task::LocalSet;
+
+// A resource whose cleanup captures `!Send` state (an `Rc`).
+struct LocalResource {
+    flag: Rc<Cell<bool>>,
+}
+
+impl AsyncDropLocal for LocalResource {
+    type Dropper = Pin<Box<dyn Future<Output = ()>>>;
+
+    fn async_drop(self) -> Self::Dropper {
+        let flag = self.flag.clone();
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            flag.set(true);
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_local_async_drop_runs_inside_local_set() {
+    let flag = Rc::new(Cell::new(false));
+    let local = LocalSet::new();
+
+    local
+        .run_until(async {
+            {
+                let _wrapper = LocalAsyncDropx::new(LocalResource { flag: flag.clone() });
+            }
+            // Let the spawned local task make progress.
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        })
+        .await;
+
+    assert!(flag.get(), "Local async drop did not run inside the LocalSet");
+}
@@ -0,0 +1,56 @@
+use async_dropx::{AsyncDrop, AsyncDropx, join_all_pending, join_all_pending_timeout};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use std::pin::Pin;
+use std::future::Future;
+
+struct Counter {
+    finished: Arc<AtomicUsize>,
+    delay: Duration,
+}
+
+impl AsyncDrop for Counter {
+    type Dropper = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn async_drop(self) -> Self::Dropper {
+        let finished = self.finished.clone();
+        let delay = self.delay;
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            finished.fetch_add(1, Ordering::SeqCst);
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_join_all_pending_waits_for_detached_cleanups() {
+    let finished = Arc::new(AtomicUsize::new(0));
+
+    {
+        for _ in 0..5 {
+            let _wrapper = AsyncDropx::new(Counter {
+                finished: finished.clone(),
+                delay: Duration::from_millis(20),
+            });
+        }
+    }
+
+    join_all_pending().await;
+    assert_eq!(finished.load(Ordering::SeqCst), 5, "Not all cleanups completed before join returned");
+}
+
+#[tokio::test]
+async fn test_join_all_pending_timeout_reports_deadline() {
+    let finished = Arc::new(AtomicUsize::new(0));
+
+    {
+        let _wrapper = AsyncDropx::new(Counter {
+            finished: finished.clone(),
+            delay: Duration::from_secs(10),
+        });
+    }
+
+    let drained = join_all_pending_timeout(Duration::from_millis(20)).await;
+    assert!(!drained, "Timeout should have elapsed before the slow cleanup finished");
+}